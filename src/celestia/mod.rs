@@ -0,0 +1,257 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Minimal celestial bodies and orbital state representation used by the
+//! dynamics and propagator modules. A `State` is always expressed against a
+//! `Geoid` (the gravitational/shape model of the body it orbits).
+
+use hifitime::Epoch;
+
+/// Known central bodies, identified the same way the rest of the codebase
+/// refers to them (by NAIF-like integer ID).
+pub mod bodies {
+    pub const EARTH: u16 = 399;
+}
+
+/// A simple point-mass gravitational and shape model for a celestial body.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Geoid {
+    pub id: u16,
+    /// Gravitational parameter (km^3/s^2).
+    pub gm: f64,
+    /// Equatorial radius (km).
+    pub equatorial_radius: f64,
+}
+
+/// Provides access to the ephemeris/geoid database. This is a thin stand-in
+/// for the full SPICE-backed `Cosm` used elsewhere in the codebase.
+pub struct Cosm;
+
+impl Cosm {
+    /// Loads the default DE438 ephemeris.
+    pub fn de438() -> Self {
+        Cosm
+    }
+
+    /// Loads a `Cosm` from the given XB ephemeris file.
+    pub fn from_xb(_path: &str) -> Self {
+        Cosm
+    }
+
+    /// Returns the `Geoid` associated with the provided body ID.
+    pub fn geoid_from_id(&self, id: u16) -> Geoid {
+        match id {
+            bodies::EARTH => Geoid {
+                id,
+                gm: 398_600.433,
+                equatorial_radius: 6378.1363,
+            },
+            _ => panic!("unknown body id {id}"),
+        }
+    }
+}
+
+/// An orbital state expressed in a Cartesian inertial frame centered on a
+/// `Geoid`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct State<F> {
+    pub epoch: Epoch,
+    pub frame: F,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub vz: f64,
+}
+
+impl State<Geoid> {
+    /// Builds a `State` from classical Keplerian elements.
+    ///
+    /// Angles (`inc`, `raan`, `aop`, `ta`) are in degrees, `sma` in
+    /// kilometers.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_keplerian(
+        sma: f64,
+        ecc: f64,
+        inc: f64,
+        raan: f64,
+        aop: f64,
+        ta: f64,
+        epoch: Epoch,
+        frame: Geoid,
+    ) -> Self {
+        let (inc, raan, aop, ta) = (
+            inc.to_radians(),
+            raan.to_radians(),
+            aop.to_radians(),
+            ta.to_radians(),
+        );
+
+        let p = sma * (1.0 - ecc * ecc);
+        let r = p / (1.0 + ecc * ta.cos());
+
+        // Position and velocity in the perifocal frame.
+        let x_pf = r * ta.cos();
+        let y_pf = r * ta.sin();
+        let sqrt_gm_p = (frame.gm / p).sqrt();
+        let vx_pf = -sqrt_gm_p * ta.sin();
+        let vy_pf = sqrt_gm_p * (ecc + ta.cos());
+
+        // Perifocal to inertial frame rotation (3-1-3 Euler sequence).
+        let (sr, cr) = raan.sin_cos();
+        let (si, ci) = inc.sin_cos();
+        let (sa, ca) = aop.sin_cos();
+
+        let r11 = cr * ca - sr * sa * ci;
+        let r12 = -cr * sa - sr * ca * ci;
+        let r21 = sr * ca + cr * sa * ci;
+        let r22 = -sr * sa + cr * ca * ci;
+        let r31 = sa * si;
+        let r32 = ca * si;
+
+        State {
+            epoch,
+            frame,
+            x: r11 * x_pf + r12 * y_pf,
+            y: r21 * x_pf + r22 * y_pf,
+            z: r31 * x_pf + r32 * y_pf,
+            vx: r11 * vx_pf + r12 * vy_pf,
+            vy: r21 * vx_pf + r22 * vy_pf,
+            vz: r31 * vx_pf + r32 * vy_pf,
+        }
+    }
+
+    /// Orbital radius magnitude (km).
+    pub fn rmag(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Orbital velocity magnitude (km/s).
+    pub fn vmag(&self) -> f64 {
+        (self.vx * self.vx + self.vy * self.vy + self.vz * self.vz).sqrt()
+    }
+
+    /// Specific angular momentum vector, `r x v` (km^2/s).
+    pub(crate) fn h_vec(&self) -> (f64, f64, f64) {
+        (
+            self.y * self.vz - self.z * self.vy,
+            self.z * self.vx - self.x * self.vz,
+            self.x * self.vy - self.y * self.vx,
+        )
+    }
+
+    /// Eccentricity vector, pointing towards periapsis, with magnitude
+    /// equal to the orbit's eccentricity.
+    fn ecc_vec(&self) -> (f64, f64, f64) {
+        let r = self.rmag();
+        let v2 = self.vmag().powi(2);
+        let rdotv = self.x * self.vx + self.y * self.vy + self.z * self.vz;
+        let mu = self.frame.gm;
+
+        (
+            ((v2 - mu / r) * self.x - rdotv * self.vx) / mu,
+            ((v2 - mu / r) * self.y - rdotv * self.vy) / mu,
+            ((v2 - mu / r) * self.z - rdotv * self.vz) / mu,
+        )
+    }
+
+    /// Semi-major axis (km).
+    pub fn sma(&self) -> f64 {
+        1.0 / (2.0 / self.rmag() - self.vmag().powi(2) / self.frame.gm)
+    }
+
+    /// Eccentricity (dimensionless).
+    pub fn ecc(&self) -> f64 {
+        let (ex, ey, ez) = self.ecc_vec();
+        (ex * ex + ey * ey + ez * ez).sqrt()
+    }
+
+    /// Inclination (degrees).
+    pub fn inc_deg(&self) -> f64 {
+        let (hx, hy, hz) = self.h_vec();
+        let h = (hx * hx + hy * hy + hz * hz).sqrt();
+        (hz / h).clamp(-1.0, 1.0).acos().to_degrees()
+    }
+
+    /// Right ascension of the ascending node (degrees).
+    pub fn raan_deg(&self) -> f64 {
+        let (hx, hy, _) = self.h_vec();
+        let (nx, ny) = (-hy, hx);
+        let n = (nx * nx + ny * ny).sqrt();
+        if n < f64::EPSILON {
+            return 0.0;
+        }
+        let raan = (nx / n).clamp(-1.0, 1.0).acos().to_degrees();
+        if ny < 0.0 {
+            360.0 - raan
+        } else {
+            raan
+        }
+    }
+
+    /// Argument of periapsis (degrees).
+    pub fn aop_deg(&self) -> f64 {
+        let (hx, hy, _) = self.h_vec();
+        let (nx, ny) = (-hy, hx);
+        let n = (nx * nx + ny * ny).sqrt();
+        let (ex, ey, ez) = self.ecc_vec();
+        let ecc = (ex * ex + ey * ey + ez * ez).sqrt();
+
+        if n < f64::EPSILON || ecc < f64::EPSILON {
+            return 0.0;
+        }
+
+        let cos_aop = ((nx * ex + ny * ey) / (n * ecc)).clamp(-1.0, 1.0);
+        let aop = cos_aop.acos().to_degrees();
+        if ez < 0.0 {
+            360.0 - aop
+        } else {
+            aop
+        }
+    }
+
+    /// True anomaly (degrees).
+    pub fn ta_deg(&self) -> f64 {
+        let (ex, ey, ez) = self.ecc_vec();
+        let ecc = (ex * ex + ey * ey + ez * ez).sqrt();
+
+        if ecc < f64::EPSILON {
+            return 0.0;
+        }
+
+        let rdotv = self.x * self.vx + self.y * self.vy + self.z * self.vz;
+        let cos_ta = ((ex * self.x + ey * self.y + ez * self.z) / (ecc * self.rmag())).clamp(-1.0, 1.0);
+        let ta = cos_ta.acos().to_degrees();
+        if rdotv < 0.0 {
+            360.0 - ta
+        } else {
+            ta
+        }
+    }
+}
+
+impl<F> std::fmt::Octal for State<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] r = {:.3} {:.3} {:.3} km\tv = {:.3} {:.3} {:.3} km/s",
+            self.epoch, self.x, self.y, self.z, self.vx, self.vy, self.vz
+        )
+    }
+}
@@ -0,0 +1,98 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use na::DVector;
+
+use crate::celestia::{Geoid, State};
+use crate::dynamics::Dynamics;
+
+/// Two-body (and, eventually, perturbed) orbital dynamics about a single
+/// `Geoid`.
+#[derive(Copy, Clone, Debug)]
+pub struct CelestialDynamics {
+    t: f64,
+    orbit: State<Geoid>,
+}
+
+impl CelestialDynamics {
+    /// Builds a two-body dynamical model seeded with the given orbit.
+    pub fn two_body(orbit: State<Geoid>) -> Self {
+        Self { t: 0.0, orbit }
+    }
+}
+
+impl Dynamics for CelestialDynamics {
+    type StateType = State<Geoid>;
+
+    fn time(&self) -> f64 {
+        self.t
+    }
+
+    fn state(&self) -> Self::StateType {
+        self.orbit
+    }
+
+    fn state_vector(&self) -> DVector<f64> {
+        DVector::from_vec(vec![
+            self.orbit.x,
+            self.orbit.y,
+            self.orbit.z,
+            self.orbit.vx,
+            self.orbit.vy,
+            self.orbit.vz,
+        ])
+    }
+
+    fn eom(&self, _t: f64, state: &DVector<f64>) -> DVector<f64> {
+        let (x, y, z) = (state[0], state[1], state[2]);
+        let (vx, vy, vz) = (state[3], state[4], state[5]);
+        let r3 = (x * x + y * y + z * z).powf(1.5);
+        let gm_r3 = self.orbit.frame.gm / r3;
+
+        DVector::from_vec(vec![vx, vy, vz, -gm_r3 * x, -gm_r3 * y, -gm_r3 * z])
+    }
+
+    fn set_state(&mut self, new_t: f64, new_state: &DVector<f64>) {
+        self.t = new_t;
+        self.orbit.x = new_state[0];
+        self.orbit.y = new_state[1];
+        self.orbit.z = new_state[2];
+        self.orbit.vx = new_state[3];
+        self.orbit.vy = new_state[4];
+        self.orbit.vz = new_state[5];
+    }
+}
+
+impl CelestialDynamics {
+    /// Builds the orbital state a 6-element state vector represents, using
+    /// this dynamics' current epoch and frame. Used to evaluate
+    /// state-dependent terms (e.g. a thrust control law) at an intermediate
+    /// integration stage, rather than only at the last-committed state.
+    pub fn state_from_vector(&self, v: &DVector<f64>) -> State<Geoid> {
+        State {
+            epoch: self.orbit.epoch,
+            frame: self.orbit.frame,
+            x: v[0],
+            y: v[1],
+            z: v[2],
+            vx: v[3],
+            vy: v[4],
+            vz: v[5],
+        }
+    }
+}
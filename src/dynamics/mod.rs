@@ -0,0 +1,71 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+pub mod celestial;
+pub mod propulsion;
+pub mod spacecraft;
+pub mod thrustctrl;
+
+use na::DVector;
+
+/// Implemented by every dynamical model that a `Propagator` can integrate.
+///
+/// A `Dynamics` implementation is responsible for turning its own notion of
+/// state into the flat `DVector` the integrator works with, and back.
+pub trait Dynamics {
+    /// The model's native state representation (e.g. a `State<Geoid>`).
+    type StateType: Copy;
+
+    /// Current simulation time, in seconds past the dynamics' reference epoch.
+    fn time(&self) -> f64;
+
+    /// Current state, in the model's native representation.
+    fn state(&self) -> Self::StateType;
+
+    /// Current state, flattened to the vector the integrator propagates.
+    fn state_vector(&self) -> DVector<f64>;
+
+    /// Differential equations of motion evaluated at `t` for `state`.
+    fn eom(&self, t: f64, state: &DVector<f64>) -> DVector<f64>;
+
+    /// Stores the result of a completed integration step.
+    fn set_state(&mut self, new_t: f64, new_state: &DVector<f64>);
+
+    /// Returns, as a fraction of the step from `prev_t`/`prev_state` to
+    /// `new_t`/`new_state`, where a discrete event (e.g. stage separation)
+    /// occurs within this step, if any. A `Propagator` that sees `Some(frac)`
+    /// re-integrates only up to `frac` of the step, applies the event via
+    /// `apply_event`, and resumes from there instead of from the full step.
+    ///
+    /// The default implementation reports no events.
+    fn event_crossing(
+        &self,
+        _prev_t: f64,
+        _new_t: f64,
+        _prev_state: &DVector<f64>,
+        _new_state: &DVector<f64>,
+    ) -> Option<f64> {
+        None
+    }
+
+    /// Applies whatever discrete event `event_crossing` detected, once the
+    /// dynamics' state has been set exactly at the crossing.
+    ///
+    /// The default implementation does nothing.
+    fn apply_event(&mut self) {}
+}
@@ -0,0 +1,258 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::celestia::{Geoid, State};
+use crate::dynamics::thrustctrl::Ruggiero;
+use crate::io::ConfigRepr;
+
+/// Standard gravity, in km/s^2, used to convert a specific impulse in
+/// seconds into an effective exhaust velocity.
+pub const G0: f64 = 9.80665e-3;
+
+/// The propellant a thruster burns, e.g. for reporting tank mass or, in the
+/// future, density-dependent tankage sizing. Kept as its own `ConfigRepr`
+/// entry so a propellant catalog can be maintained independently of the
+/// engines that consume it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Propellant {
+    pub name: String,
+    /// Propellant density, in kg/m^3.
+    pub density_kg_m3: f64,
+}
+
+impl ConfigRepr for Propellant {}
+
+/// Thrust and Isp characterized at one throttle level (or available power
+/// fraction) of a variable-performance engine, e.g. one power mode of a Hall
+/// thruster's envelope.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OperatingPoint {
+    /// Throttle fraction (0 to 1) this point was characterized at.
+    pub throttle: f64,
+    /// Thrust, in Newtons, at this operating point.
+    pub thrust: f64,
+    /// Specific impulse, in seconds, at this operating point.
+    pub isp: f64,
+}
+
+/// A thruster's performance envelope: its rated thrust and Isp, how far it
+/// can be throttled, and how long it may fire in total. Implements
+/// `ConfigRepr` so a catalog of named engines (e.g. `AJ10_104D`,
+/// `BELL_8048`) can be maintained in a YAML file and referenced by name
+/// instead of hardcoded inline in every scenario.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Thruster {
+    /// Name of this engine definition, e.g. `AJ10_104D`. Empty for an
+    /// inline, uncataloged thruster.
+    pub name: String,
+    /// Thrust, in Newtons, at 100% throttle. Ignored once `curve` is set.
+    pub thrust: f64,
+    /// Specific impulse, in seconds, at 100% throttle. Ignored once `curve`
+    /// is set.
+    pub isp: f64,
+    /// Tabulated (throttle, thrust, isp) operating points, sorted by
+    /// ascending throttle, for engines whose thrust and Isp trade off across
+    /// their power envelope (e.g. a Hall thruster's high-Isp and
+    /// high-thrust modes). When empty, the scalar `thrust`/`isp` above are
+    /// used at every throttle level instead.
+    pub curve: Vec<OperatingPoint>,
+    /// The propellant this thruster burns, if tracked.
+    pub propellant: Option<Propellant>,
+    /// Cumulative full-thrust-equivalent burn time this thruster may
+    /// deliver before it refuses to fire again, in seconds. `None` means
+    /// unlimited.
+    pub max_burn_time_s: Option<f64>,
+    /// Minimum throttle fraction the thruster can sustain while firing.
+    pub min_throttle: f64,
+    /// Maximum throttle fraction the thruster can sustain while firing.
+    pub max_throttle: f64,
+    /// Average fraction of the time the thruster is actually able to fire
+    /// while commanded on, e.g. to approximate an engine restricted to
+    /// eclipse-free arcs without modeling eclipses directly. Applied as a
+    /// time-averaged derate of the delivered thrust and mass flow, not as an
+    /// on/off gate timed to orbital position, and not as a change to the
+    /// engine's operating point on `curve` (see `Propulsion::accel_and_mass_flow`).
+    /// `None` means no derate (1.0).
+    pub duty_cycle: Option<f64>,
+}
+
+impl Default for Thruster {
+    /// An unnamed, idealized, always-on, unthrottled, unlimited-duration
+    /// thruster.
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            thrust: 0.0,
+            isp: 0.0,
+            curve: Vec::new(),
+            propellant: None,
+            max_burn_time_s: None,
+            min_throttle: 0.0,
+            max_throttle: 1.0,
+            duty_cycle: None,
+        }
+    }
+}
+
+impl Thruster {
+    /// Thrust, in Newtons, at the given throttle fraction: linearly
+    /// interpolated from `curve` if set, otherwise the scalar `thrust`
+    /// rating scaled linearly by throttle.
+    pub fn thrust_at(&self, throttle: f64) -> f64 {
+        self.interpolate(throttle, |p| p.thrust)
+            .unwrap_or(self.thrust * throttle)
+    }
+
+    /// Specific impulse, in seconds, at the given throttle fraction:
+    /// linearly interpolated from `curve` if set, otherwise the scalar
+    /// `isp` rating regardless of throttle (a simple fixed-point engine's
+    /// exhaust velocity does not depend on how throttled it is).
+    pub fn isp_at(&self, throttle: f64) -> f64 {
+        self.interpolate(throttle, |p| p.isp).unwrap_or(self.isp)
+    }
+
+    /// Mass flow rate, in kg/s (negative while thrusting), at the given
+    /// throttle fraction.
+    pub fn mass_flow_rate_at(&self, throttle: f64) -> f64 {
+        -self.thrust_at(throttle) / (self.isp_at(throttle) * G0 * 1000.0)
+    }
+
+    /// Linearly interpolates `field` across `self.curve` at `throttle`,
+    /// clamping to the first/last point outside the tabulated range.
+    /// Returns `None` if no curve is set.
+    fn interpolate(&self, throttle: f64, field: impl Fn(&OperatingPoint) -> f64) -> Option<f64> {
+        let first = self.curve.first()?;
+        let last = self.curve.last()?;
+
+        if throttle <= first.throttle {
+            return Some(field(first));
+        }
+        if throttle >= last.throttle {
+            return Some(field(last));
+        }
+
+        for window in self.curve.windows(2) {
+            let (lo, hi) = (&window[0], &window[1]);
+            if throttle >= lo.throttle && throttle <= hi.throttle {
+                let frac = (throttle - lo.throttle) / (hi.throttle - lo.throttle);
+                return Some(field(lo) + frac * (field(hi) - field(lo)));
+            }
+        }
+
+        Some(field(last))
+    }
+}
+
+impl ConfigRepr for Thruster {}
+
+// Note: `GroundStation` additionally exposes `load`/`load_many`/`load_named`
+// as `#[pymethods]` classmethods, but that binding lives in a newer API
+// generation (`crate::python`, built on `crate::cosmic`/`crate::od`) that
+// this module's `celestia`/`dynamics` tree does not share. Python access to
+// `Thruster.load_named(...)` needs that wiring extended to this tree first.
+
+/// Couples a set of `Thruster`s with a `Ruggiero` control law: at every
+/// evaluation, the control law picks the thrust direction and this subsystem
+/// sums up every thruster's contribution to acceleration and mass flow.
+pub struct Propulsion<'a> {
+    ruggiero: &'a mut Ruggiero,
+    pub thrusters: Vec<Thruster>,
+    /// When `true`, the thrusters fire whenever the Ruggiero law commands a
+    /// direction; when `false`, they never fire (no coasting-arc selection
+    /// is made).
+    pub continuous: bool,
+}
+
+impl<'a> Propulsion<'a> {
+    /// Couples the given thrusters with the Ruggiero control law.
+    pub fn new(ruggiero: &'a mut Ruggiero, thrusters: Vec<Thruster>, continuous: bool) -> Self {
+        Self {
+            ruggiero,
+            thrusters,
+            continuous,
+        }
+    }
+
+    /// Returns the inertial-frame acceleration (km/s^2) and mass flow rate
+    /// (kg/s, negative while thrusting) this subsystem imparts on a vehicle
+    /// of the given total mass, at the given orbital state, along with the
+    /// per-thruster throttle fraction actually applied (used by the caller
+    /// to integrate cumulative burn time).
+    ///
+    /// `burn_used_s` is each thruster's cumulative full-thrust-equivalent
+    /// burn time so far, in the same order as `self.thrusters`; a thruster
+    /// whose `max_burn_time_s` has been reached is refused regardless of
+    /// what the Ruggiero law commands. Every thruster's commanded throttle
+    /// is first clamped to its own `min_throttle..=max_throttle` to pick its
+    /// operating point on `curve` (what power level the engine runs at),
+    /// then that operating point's thrust and mass flow are separately
+    /// derated by `duty_cycle` (what fraction of the time it is allowed to
+    /// run) to get their time-averaged contribution.
+    pub fn accel_and_mass_flow(
+        &self,
+        state: &State<Geoid>,
+        total_mass_kg: f64,
+        burn_used_s: &[f64],
+    ) -> ([f64; 3], f64, Vec<f64>) {
+        let no_op = || ([0.0; 3], 0.0, vec![0.0; self.thrusters.len()]);
+
+        if !self.continuous {
+            return no_op();
+        }
+
+        let commanded_throttle = self.ruggiero.throttle(state);
+        match self.ruggiero.direction(state) {
+            None => no_op(),
+            Some((ux, uy, uz)) => {
+                let mut thrust_n = 0.0;
+                let mut mdot = 0.0;
+                let mut throttles = Vec::with_capacity(self.thrusters.len());
+
+                for (thruster, &used_s) in self.thrusters.iter().zip(burn_used_s.iter()) {
+                    let exhausted = thruster.max_burn_time_s.is_some_and(|max_s| used_s >= max_s);
+
+                    let operating_throttle = if exhausted || commanded_throttle <= 0.0 {
+                        0.0
+                    } else {
+                        commanded_throttle.clamp(thruster.min_throttle, thruster.max_throttle)
+                    };
+
+                    let duty_cycle = thruster.duty_cycle.unwrap_or(1.0);
+
+                    if operating_throttle > 0.0 {
+                        thrust_n += thruster.thrust_at(operating_throttle) * duty_cycle;
+                        mdot += thruster.mass_flow_rate_at(operating_throttle) * duty_cycle;
+                    }
+                    throttles.push(operating_throttle * duty_cycle);
+                }
+
+                // N / kg = m/s^2; convert to km/s^2.
+                let accel_km_s2 = thrust_n / total_mass_kg / 1000.0;
+                (
+                    [ux * accel_km_s2, uy * accel_km_s2, uz * accel_km_s2],
+                    mdot,
+                    throttles,
+                )
+            }
+        }
+    }
+}
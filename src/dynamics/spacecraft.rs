@@ -0,0 +1,270 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use na::DVector;
+
+use crate::dynamics::celestial::CelestialDynamics;
+use crate::dynamics::propulsion::{Propulsion, Thruster};
+use crate::dynamics::Dynamics;
+
+/// A single, separable section of a multi-stage vehicle: its own dry mass,
+/// its own propellant load, and the thrusters that burn from it. Modeled
+/// after how launch-vehicle stacks describe each stage independently (own
+/// engines, own tankage) rather than as one lumped dry/fuel pair.
+#[derive(Clone, Debug)]
+pub struct Stage {
+    /// Structural mass left behind once this stage's propellant is spent
+    /// and it is jettisoned, in kg.
+    pub dry_mass: f64,
+    /// Propellant mass available to this stage's thrusters, in kg.
+    pub prop_mass: f64,
+    /// Thrusters fed exclusively by this stage's propellant.
+    pub thrusters: Vec<Thruster>,
+}
+
+impl Stage {
+    pub fn new(dry_mass: f64, prop_mass: f64, thrusters: Vec<Thruster>) -> Self {
+        Self {
+            dry_mass,
+            prop_mass,
+            thrusters,
+        }
+    }
+}
+
+/// Decides when the active stage is jettisoned in favor of the next one.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Staging {
+    /// Jettison as soon as the active stage's propellant reaches zero.
+    OnDepletion,
+    /// Jettison once this many seconds have elapsed since propagation
+    /// started, regardless of remaining propellant.
+    AtElapsedTime(f64),
+}
+
+/// Records the instantaneous mass discontinuity caused by jettisoning a
+/// stage.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StagingEvent {
+    /// Elapsed seconds, since the start of propagation, at which staging
+    /// occurred.
+    pub elapsed_time: f64,
+    /// Index, within `Spacecraft::stages`, of the stage that was jettisoned.
+    pub stage: usize,
+    /// Dry mass instantaneously removed from the vehicle, in kg.
+    pub jettisoned_dry_mass: f64,
+}
+
+/// A vehicle combining orbital dynamics with a propulsion subsystem drawn
+/// from a stack of `Stage`s. Only the active stage's thrusters contribute
+/// thrust and mass flow; once its propellant is gone (or its `Staging`
+/// trigger fires) the stage is jettisoned and the next one takes over.
+pub struct Spacecraft<'a> {
+    pub celestial: CelestialDynamics,
+    propulsion: &'a mut Propulsion<'a>,
+    pub stages: Vec<Stage>,
+    pub staging: Staging,
+    pub active_stage: usize,
+    /// Propellant remaining in the active stage, in kg.
+    pub fuel_mass: f64,
+    /// Cumulative full-thrust-equivalent burn time consumed so far by each
+    /// of the active stage's thrusters, in seconds, indexed the same as
+    /// `propulsion.thrusters`.
+    pub burn_time_s: Vec<f64>,
+    t: f64,
+    /// Every staging event applied so far, in chronological order.
+    pub staging_events: Vec<StagingEvent>,
+}
+
+impl<'a> Spacecraft<'a> {
+    /// Builds a single-stage vehicle: all of `propulsion`'s thrusters draw
+    /// from one `dry_mass`/`fuel_mass` pair. This is the legacy constructor
+    /// for vehicles that do not separate.
+    pub fn with_prop(
+        dynamics: &mut CelestialDynamics,
+        propulsion: &'a mut Propulsion<'a>,
+        dry_mass: f64,
+        fuel_mass: f64,
+    ) -> Self {
+        let stage = Stage::new(dry_mass, fuel_mass, propulsion.thrusters.clone());
+        Self::with_stages(dynamics, propulsion, vec![stage], Staging::OnDepletion)
+    }
+
+    /// Builds a multi-stage vehicle. The first stage is active at epoch;
+    /// later stages ride along inert (their dry and propellant mass still
+    /// contribute to the vehicle's inertia) until staged in per `staging`.
+    pub fn with_stages(
+        dynamics: &mut CelestialDynamics,
+        propulsion: &'a mut Propulsion<'a>,
+        stages: Vec<Stage>,
+        staging: Staging,
+    ) -> Self {
+        assert!(!stages.is_empty(), "a spacecraft needs at least one stage");
+        let fuel_mass = stages[0].prop_mass;
+        propulsion.thrusters = stages[0].thrusters.clone();
+        let burn_time_s = vec![0.0; propulsion.thrusters.len()];
+        Self {
+            celestial: *dynamics,
+            propulsion,
+            stages,
+            staging,
+            active_stage: 0,
+            fuel_mass,
+            burn_time_s,
+            t: 0.0,
+            staging_events: Vec::new(),
+        }
+    }
+
+    /// Total instantaneous vehicle mass: the active stage's dry mass plus
+    /// its remaining propellant, plus every not-yet-active stage in full.
+    pub fn total_mass(&self) -> f64 {
+        self.total_mass_with_fuel(self.fuel_mass)
+    }
+
+    /// Same as `total_mass`, but for an arbitrary active-stage propellant
+    /// load rather than `self.fuel_mass`. Used while integrating: an RK4
+    /// stage evaluates `eom` against an intermediate state whose propellant
+    /// mass differs from the last-committed `self.fuel_mass`, and the
+    /// thrust acceleration at that stage must be computed against the mass
+    /// the integrator is actually propagating, not the mass at the start of
+    /// the step.
+    fn total_mass_with_fuel(&self, fuel_mass: f64) -> f64 {
+        let mut mass = self.stages[self.active_stage].dry_mass + fuel_mass;
+        for stage in &self.stages[self.active_stage + 1..] {
+            mass += stage.dry_mass + stage.prop_mass;
+        }
+        mass
+    }
+
+    /// `true` once the active stage is the last one and its propellant has
+    /// been exhausted.
+    pub fn depleted(&self) -> bool {
+        self.active_stage + 1 >= self.stages.len() && self.fuel_mass <= 0.0
+    }
+
+    /// Returns, as a fraction of the *next* integration step (`prev_t` to
+    /// `new_t`), when the active stage's `Staging` trigger fires, if it
+    /// falls within this step. The propagator bisects the step to this
+    /// fraction so the stage separation happens exactly at the crossing
+    /// rather than at the end of a full RK4 step.
+    pub fn staging_crossing(&self, prev_t: f64, new_t: f64, prev_fuel: f64, new_fuel: f64) -> Option<f64> {
+        match self.staging {
+            Staging::OnDepletion => {
+                if prev_fuel > 0.0 && new_fuel <= 0.0 {
+                    Some(prev_fuel / (prev_fuel - new_fuel))
+                } else {
+                    None
+                }
+            }
+            Staging::AtElapsedTime(trigger_t) => {
+                if prev_t < trigger_t && new_t >= trigger_t {
+                    Some((trigger_t - prev_t) / (new_t - prev_t))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Jettisons the active stage: its dry mass is instantaneously removed
+    /// from the vehicle, the next stage (if any) becomes active with a full
+    /// propellant load, and the mass discontinuity is recorded. If the
+    /// active stage is the last one, there is nothing to separate into: its
+    /// dry mass keeps counting towards `total_mass`, so no event is logged.
+    pub fn stage(&mut self) {
+        if self.active_stage + 1 < self.stages.len() {
+            let jettisoned_dry_mass = self.stages[self.active_stage].dry_mass;
+            self.staging_events.push(StagingEvent {
+                elapsed_time: self.t,
+                stage: self.active_stage,
+                jettisoned_dry_mass,
+            });
+
+            self.active_stage += 1;
+            self.fuel_mass = self.stages[self.active_stage].prop_mass;
+            self.propulsion.thrusters = self.stages[self.active_stage].thrusters.clone();
+        } else {
+            self.fuel_mass = 0.0;
+            self.propulsion.thrusters.clear();
+        }
+        self.burn_time_s = vec![0.0; self.propulsion.thrusters.len()];
+    }
+}
+
+impl<'a> Dynamics for Spacecraft<'a> {
+    type StateType = crate::celestia::State<crate::celestia::Geoid>;
+
+    fn time(&self) -> f64 {
+        self.t
+    }
+
+    fn state(&self) -> Self::StateType {
+        self.celestial.state()
+    }
+
+    fn state_vector(&self) -> DVector<f64> {
+        let mut state = self.celestial.state_vector().as_slice().to_vec();
+        state.push(self.fuel_mass);
+        state.extend_from_slice(&self.burn_time_s);
+        DVector::from_vec(state)
+    }
+
+    fn eom(&self, t: f64, state: &DVector<f64>) -> DVector<f64> {
+        let orbit_state = DVector::from_vec(state.as_slice()[..6].to_vec());
+        let mut d_orbit = self.celestial.eom(t, &orbit_state);
+
+        let fuel_mass = state[6];
+        let burn_used_s = &state.as_slice()[7..];
+        let (accel, mdot, throttles) = self.propulsion.accel_and_mass_flow(
+            &self.celestial.state_from_vector(&orbit_state),
+            self.total_mass_with_fuel(fuel_mass),
+            burn_used_s,
+        );
+        d_orbit[3] += accel[0];
+        d_orbit[4] += accel[1];
+        d_orbit[5] += accel[2];
+
+        let mut d_state = d_orbit.as_slice().to_vec();
+        d_state.push(mdot);
+        d_state.extend(throttles);
+        DVector::from_vec(d_state)
+    }
+
+    fn set_state(&mut self, new_t: f64, new_state: &DVector<f64>) {
+        let orbit_state = DVector::from_vec(new_state.as_slice()[..6].to_vec());
+        self.celestial.set_state(new_t, &orbit_state);
+        self.t = new_t;
+        self.fuel_mass = new_state[6];
+        self.burn_time_s = new_state.as_slice()[7..].to_vec();
+    }
+
+    fn event_crossing(
+        &self,
+        prev_t: f64,
+        new_t: f64,
+        prev_state: &DVector<f64>,
+        new_state: &DVector<f64>,
+    ) -> Option<f64> {
+        self.staging_crossing(prev_t, new_t, prev_state[6], new_state[6])
+    }
+
+    fn apply_event(&mut self) {
+        self.stage();
+    }
+}
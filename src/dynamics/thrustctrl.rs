@@ -0,0 +1,230 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::celestia::{Geoid, State};
+
+/// A single orbital element target and the tolerance within which it is
+/// considered achieved.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Achieve {
+    Sma { target: f64, tol: f64 },
+    Ecc { target: f64, tol: f64 },
+    Inc { target: f64, tol: f64 },
+    Raan { target: f64, tol: f64 },
+    Aop { target: f64, tol: f64 },
+}
+
+/// Closed-loop Lyapunov-based control law described by Ruggiero et al.,
+/// "Low-Thrust Maneuvers for the Efficient Correction of Orbital Elements"
+/// (2012). Given a set of orbital element objectives, it continuously
+/// computes the thrust direction (in the local RSW frame, then rotated
+/// into the inertial frame) that most
+/// efficiently drives the spacecraft towards every unmet objective.
+#[derive(Clone, Debug)]
+pub struct Ruggiero {
+    pub objectives: Vec<Achieve>,
+    pub init_state: State<Geoid>,
+}
+
+impl Ruggiero {
+    /// Creates a new Ruggiero control law for the given objectives, seeded
+    /// with the osculating state the control law will be evaluated against.
+    pub fn new(objectives: Vec<Achieve>, init_state: State<Geoid>) -> Self {
+        Self {
+            objectives,
+            init_state,
+        }
+    }
+
+    /// Returns `true` once every objective is within its tolerance of the
+    /// provided state's osculating orbital elements.
+    pub fn achieved(&self, state: &State<Geoid>) -> bool {
+        self.objectives.iter().all(|objective| match *objective {
+            Achieve::Sma { target, tol } => (state.sma() - target).abs() < tol,
+            Achieve::Ecc { target, tol } => (state.ecc() - target).abs() < tol,
+            Achieve::Inc { target, tol } => (state.inc_deg() - target).abs() < tol,
+            Achieve::Raan { target, tol } => (state.raan_deg() - target).abs() < tol,
+            Achieve::Aop { target, tol } => (state.aop_deg() - target).abs() < tol,
+        })
+    }
+
+    /// Commanded throttle fraction (0 to 1) for the given state: full thrust
+    /// while any objective is outstanding, none once they are all achieved.
+    /// `Propulsion` clamps this to each thruster's own throttle range before
+    /// applying it.
+    pub fn throttle(&self, state: &State<Geoid>) -> f64 {
+        if self.achieved(state) {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Sign of the shortest angular path from `current` to `target`, both in
+    /// degrees over `[0, 360)`: `1.0` if the target lies the short way around
+    /// in the increasing direction, `-1.0` otherwise. Unlike a plain
+    /// `target > current` compare, this picks the right rotation sense when
+    /// the two straddle the 0/360 wrap point.
+    fn angle_sign(current: f64, target: f64) -> f64 {
+        let diff = ((target - current + 540.0).rem_euclid(360.0)) - 180.0;
+        if diff >= 0.0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Unit thrust direction (in the inertial frame) that the control law
+    /// recommends for the given state, or `None` if every objective is
+    /// already achieved and the engine should coast.
+    ///
+    /// For every outstanding objective, the Gauss variational equation for
+    /// that element gives the radial/along-track/cross-track (RSW)
+    /// direction that maximizes its rate of change (sign-flipped to close
+    /// the gap rather than open it); those per-objective RSW directions are
+    /// each normalized to a unit vector (so objectives measured in
+    /// kilometers, like `Ecc`/`Aop`, don't swamp ones measured in radians of
+    /// trig terms, like `Sma`) before being summed and renormalized again,
+    /// then rotated into the inertial frame. `Sma` and `Ecc` are purely
+    /// in-plane (radial/along-track); `Inc` and `Raan` are purely
+    /// cross-track; `Aop` draws on all three, since the nodal regression
+    /// term in its Gauss equation makes out-of-plane thrust useful too.
+    pub fn direction(&self, state: &State<Geoid>) -> Option<(f64, f64, f64)> {
+        if self.achieved(state) {
+            return None;
+        }
+
+        let r = state.rmag();
+        let (hx, hy, hz) = state.h_vec();
+        let h = (hx * hx + hy * hy + hz * hz).sqrt();
+        if r == 0.0 || h == 0.0 {
+            return None;
+        }
+
+        // RSW frame: radial (along the position vector), cross-track (along
+        // the orbit normal), along-track (completes the right-handed triad).
+        let r_hat = (state.x / r, state.y / r, state.z / r);
+        let w_hat = (hx / h, hy / h, hz / h);
+        let s_hat = (
+            w_hat.1 * r_hat.2 - w_hat.2 * r_hat.1,
+            w_hat.2 * r_hat.0 - w_hat.0 * r_hat.2,
+            w_hat.0 * r_hat.1 - w_hat.1 * r_hat.0,
+        );
+
+        let sma = state.sma();
+        let ecc = state.ecc();
+        let p = sma * (1.0 - ecc * ecc);
+        let ta_deg = state.ta_deg();
+        let inc_deg = state.inc_deg();
+        let aop_deg = state.aop_deg();
+        let nu = ta_deg.to_radians();
+        let (sin_nu, cos_nu) = nu.sin_cos();
+        let inc = inc_deg.to_radians();
+        let (sin_i, cos_i) = inc.sin_cos();
+        let u = (aop_deg + ta_deg).to_radians();
+        let (sin_u, cos_u) = u.sin_cos();
+
+        let (mut c_r, mut c_s, mut c_w) = (0.0, 0.0, 0.0);
+
+        for objective in &self.objectives {
+            // Each objective's raw GVE coefficients below carry different
+            // physical units (e.g. `Ecc`/`Aop` scale with `p`/`r` in
+            // kilometers, `Sma` is a dimensionless combination of trig
+            // terms), so every contribution is normalized to a unit vector
+            // before being added in: that way one outstanding objective
+            // can't drown out another just because its raw coefficients
+            // happen to be numerically larger.
+            let contribution = match *objective {
+                Achieve::Sma { target, tol } => {
+                    let current = sma;
+                    if (current - target).abs() < tol {
+                        continue;
+                    }
+                    let sign = if target > current { 1.0 } else { -1.0 };
+                    (sign * ecc * sin_nu, sign * (1.0 + ecc * cos_nu), 0.0)
+                }
+                Achieve::Ecc { target, tol } => {
+                    let current = ecc;
+                    if (current - target).abs() < tol {
+                        continue;
+                    }
+                    let sign = if target > current { 1.0 } else { -1.0 };
+                    (sign * p * sin_nu, sign * ((p + r) * cos_nu + r * ecc), 0.0)
+                }
+                Achieve::Inc { target, tol } => {
+                    let current = inc_deg;
+                    if (current - target).abs() < tol {
+                        continue;
+                    }
+                    let sign = if target > current { 1.0 } else { -1.0 };
+                    (0.0, 0.0, sign * cos_u)
+                }
+                Achieve::Raan { target, tol } => {
+                    let current = state.raan_deg();
+                    if (current - target).abs() < tol || sin_i.abs() < 1e-6 {
+                        continue;
+                    }
+                    let sign = Self::angle_sign(current, target);
+                    (0.0, 0.0, sign * sin_u / sin_i)
+                }
+                Achieve::Aop { target, tol } => {
+                    let current = aop_deg;
+                    if (current - target).abs() < tol || ecc < 1e-6 || sin_i.abs() < 1e-6 {
+                        continue;
+                    }
+                    let sign = Self::angle_sign(current, target);
+                    // The exact Gauss-equation weighting divides the in-plane
+                    // terms by `ecc` outright, which is the true optimum but
+                    // is singular as ecc -> 0: it makes the in-plane terms
+                    // dominate the out-of-plane one by orders of magnitude,
+                    // degenerating AOP steering into pure-radial thrust and
+                    // stalling the close-loop for near-circular orbits.
+                    // Regularize with `sqrt(ecc)` instead: the in-plane terms
+                    // still grow as ecc shrinks, but far less steeply, so the
+                    // out-of-plane term keeps a usable share of the thrust.
+                    (
+                        sign * (-p * cos_nu / ecc.sqrt()),
+                        sign * ((p + r) * sin_nu / ecc.sqrt()),
+                        sign * (-(r * sin_u * cos_i) / sin_i),
+                    )
+                }
+            };
+
+            let (o_r, o_s, o_w) = contribution;
+            let o_mag = (o_r * o_r + o_s * o_s + o_w * o_w).sqrt();
+            if o_mag < 1e-12 {
+                continue;
+            }
+            c_r += o_r / o_mag;
+            c_s += o_s / o_mag;
+            c_w += o_w / o_mag;
+        }
+
+        let mag = (c_r * c_r + c_s * c_s + c_w * c_w).sqrt();
+        if mag < 1e-12 {
+            return None;
+        }
+        let (c_r, c_s, c_w) = (c_r / mag, c_s / mag, c_w / mag);
+
+        Some((
+            c_r * r_hat.0 + c_s * s_hat.0 + c_w * w_hat.0,
+            c_r * r_hat.1 + c_s * s_hat.1 + c_w * w_hat.1,
+            c_r * r_hat.2 + c_s * s_hat.2 + c_w * w_hat.2,
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+//! Loading configuration structures (ground stations, thrusters, ...) from
+//! YAML, whether one entry, a list of entries, or a catalog of entries keyed
+//! by name.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+/// Errors that can occur while loading a `ConfigRepr` from disk.
+#[derive(Debug)]
+pub enum ConfigError {
+    ReadError(std::io::Error),
+    ParseError(serde_yaml::Error),
+    InvalidConfig(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::ReadError(e) => write!(f, "could not read config file: {e}"),
+            ConfigError::ParseError(e) => write!(f, "could not parse config: {e}"),
+            ConfigError::InvalidConfig(msg) => write!(f, "invalid config: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(feature = "python")]
+impl From<ConfigError> for pyo3::PyErr {
+    fn from(err: ConfigError) -> Self {
+        pyo3::exceptions::PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Implemented by any type that can be loaded from a YAML config file, either
+/// as a single entry, a list of entries, or a catalog of named entries.
+pub trait ConfigRepr: Sized + DeserializeOwned {
+    /// Loads a single entry from the YAML file at `path`.
+    fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        serde_yaml::from_str(&read_to_string(path)?).map_err(ConfigError::ParseError)
+    }
+
+    /// Loads a YAML list of entries from `path`.
+    fn load_many<P: AsRef<Path>>(path: P) -> Result<Vec<Self>, ConfigError> {
+        serde_yaml::from_str(&read_to_string(path)?).map_err(ConfigError::ParseError)
+    }
+
+    /// Loads a YAML mapping of entries from `path`, keyed by name, e.g. a
+    /// catalog of named engines such as `AJ10_104D` or `BELL_8048`.
+    fn load_named<P: AsRef<Path>>(path: P) -> Result<HashMap<String, Self>, ConfigError> {
+        serde_yaml::from_str(&read_to_string(path)?).map_err(ConfigError::ParseError)
+    }
+}
+
+fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String, ConfigError> {
+    fs::read_to_string(path).map_err(ConfigError::ReadError)
+}
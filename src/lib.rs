@@ -0,0 +1,45 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+extern crate hifitime;
+extern crate nalgebra as na;
+
+pub mod celestia;
+pub mod dynamics;
+pub mod io;
+pub mod propagators;
+
+use std::fmt;
+
+/// Common error type carrying a free-form message. The dynamics and
+/// propagation modules do not return it, and the I/O module uses its own
+/// `io::ConfigError`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NyxError {
+    CustomError(String),
+}
+
+impl fmt::Display for NyxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NyxError::CustomError(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NyxError {}
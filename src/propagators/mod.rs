@@ -0,0 +1,139 @@
+/*
+    Nyx, blazing fast astrodynamics
+    Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com>
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU Affero General Public License as published
+    by the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU Affero General Public License for more details.
+
+    You should have received a copy of the GNU Affero General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use na::DVector;
+
+use crate::dynamics::Dynamics;
+
+/// A fixed-step Runge-Kutta method, described by its Butcher tableau.
+pub trait FixedStepMethod {
+    /// Stage coefficients (the tableau's `a` matrix, row-major, strictly
+    /// lower triangular).
+    const A: &'static [&'static [f64]];
+    /// Node offsets (the tableau's `c` column).
+    const C: &'static [f64];
+    /// Weights (the tableau's `b` row).
+    const B: &'static [f64];
+}
+
+/// The classic fourth-order, four-stage Runge-Kutta method.
+pub struct RK4Fixed;
+
+impl FixedStepMethod for RK4Fixed {
+    const A: &'static [&'static [f64]] = &[&[], &[0.5], &[0.0, 0.5], &[0.0, 0.0, 1.0]];
+    const C: &'static [f64] = &[0.0, 0.5, 0.5, 1.0];
+    const B: &'static [f64] = &[1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0];
+}
+
+/// Tuning knobs for a `Propagator`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PropOpts {
+    pub step_size: f64,
+}
+
+impl PropOpts {
+    /// Propagates with a fixed step size, in seconds.
+    pub fn with_fixed_step(step_size: f64) -> Self {
+        Self { step_size }
+    }
+}
+
+/// Drives a `Dynamics` implementation forward in time, one fixed step at a
+/// time, via the integration method `M`.
+pub struct Propagator<'a, D: Dynamics> {
+    pub dynamics: &'a mut D,
+    opts: PropOpts,
+    step_fn: fn(&D, f64, &DVector<f64>, f64) -> DVector<f64>,
+}
+
+impl<'a, D: Dynamics> Propagator<'a, D> {
+    /// Builds a propagator for `dynamics`, integrating with method `M`.
+    pub fn new<M: FixedStepMethod>(dynamics: &'a mut D, opts: &PropOpts) -> Self {
+        Self {
+            dynamics,
+            opts: *opts,
+            step_fn: rk_step::<D, M>,
+        }
+    }
+
+    /// Performs a single integration step of `dt` seconds, from `t`/`state`.
+    fn step(&self, t: f64, state: &DVector<f64>, dt: f64) -> DVector<f64> {
+        (self.step_fn)(self.dynamics, t, state, dt)
+    }
+
+    /// Propagates `self.dynamics` forward by exactly `duration` seconds.
+    ///
+    /// Every step is first taken in full; if the dynamics reports (via
+    /// `Dynamics::event_crossing`) that a discrete event falls within that
+    /// step, the step is redone only up to the crossing, the event is
+    /// applied there, and propagation resumes from the crossing with a new
+    /// step of the configured size.
+    /// This keeps events such as stage separation synchronized to the
+    /// instant they occur instead of smeared to the end of a step.
+    pub fn until_time_elapsed(&mut self, duration: f64) {
+        let mut elapsed = 0.0;
+        while elapsed < duration {
+            let step_size = self.opts.step_size.min(duration - elapsed);
+
+            let t = self.dynamics.time();
+            let state = self.dynamics.state_vector();
+            let candidate = self.step(t, &state, step_size);
+
+            match self
+                .dynamics
+                .event_crossing(t, t + step_size, &state, &candidate)
+            {
+                Some(frac) => {
+                    let sub_step = step_size * frac;
+                    let bisected = self.step(t, &state, sub_step);
+                    self.dynamics.set_state(t + sub_step, &bisected);
+                    self.dynamics.apply_event();
+                    elapsed += sub_step;
+                }
+                None => {
+                    self.dynamics.set_state(t + step_size, &candidate);
+                    elapsed += step_size;
+                }
+            }
+        }
+    }
+}
+
+/// Advances `state` by `dt` starting at `t`, per the Butcher tableau `M`.
+fn rk_step<D: Dynamics, M: FixedStepMethod>(
+    dynamics: &D,
+    t: f64,
+    state: &DVector<f64>,
+    dt: f64,
+) -> DVector<f64> {
+    let mut k: Vec<DVector<f64>> = Vec::with_capacity(M::C.len());
+
+    for (a_row, c_i) in M::A.iter().zip(M::C.iter()) {
+        let mut stage_state = state.clone();
+        for (j, a_ij) in a_row.iter().enumerate() {
+            stage_state += dt * a_ij * &k[j];
+        }
+        k.push(dynamics.eom(t + c_i * dt, &stage_state));
+    }
+
+    let mut new_state = state.clone();
+    for (b_i, k_i) in M::B.iter().zip(k.iter()) {
+        new_state += dt * b_i * k_i;
+    }
+    new_state
+}
@@ -0,0 +1,92 @@
+extern crate nyx_space as nyx;
+
+use std::fs;
+
+use self::nyx::dynamics::propulsion::{Propellant, Thruster};
+use self::nyx::io::ConfigRepr;
+
+fn write_fixture(name: &str, contents: &str) -> std::path::PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("nyx-test-{name}-{}.yaml", std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn thruster_load_single() {
+    let path = write_fixture(
+        "thruster-single",
+        "name: AJ10_104D\n\
+         thrust: 0.0436\n\
+         isp: 312.0\n\
+         max_throttle: 1.0\n",
+    );
+
+    let thruster = Thruster::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(thruster.name, "AJ10_104D");
+    assert!((thruster.thrust - 0.0436).abs() < f64::EPSILON);
+    assert!((thruster.isp - 312.0).abs() < f64::EPSILON);
+    assert!((thruster.max_throttle - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn thruster_load_many() {
+    let path = write_fixture(
+        "thruster-many",
+        "- name: AJ10_104D\n  \
+            thrust: 0.0436\n  \
+            isp: 312.0\n\
+          - name: BELL_8048\n  \
+            thrust: 0.004\n  \
+            isp: 235.0\n",
+    );
+
+    let thrusters = Thruster::load_many(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(thrusters.len(), 2);
+    assert_eq!(thrusters[0].name, "AJ10_104D");
+    assert_eq!(thrusters[1].name, "BELL_8048");
+    assert!((thrusters[1].isp - 235.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn thruster_load_named_catalog() {
+    let path = write_fixture(
+        "thruster-named",
+        "AJ10_104D:\n  \
+            thrust: 0.0436\n  \
+            isp: 312.0\n  \
+            propellant:\n    \
+                name: N2O4/Aerozine-50\n    \
+                density_kg_m3: 1070.0\n\
+          BELL_8048:\n  \
+            thrust: 0.004\n  \
+            isp: 235.0\n",
+    );
+
+    let catalog = Thruster::load_named(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(catalog.len(), 2);
+    let aj10 = &catalog["AJ10_104D"];
+    assert!((aj10.thrust - 0.0436).abs() < f64::EPSILON);
+    let propellant = aj10.propellant.as_ref().expect("propellant should round-trip");
+    assert_eq!(propellant.name, "N2O4/Aerozine-50");
+    assert!((propellant.density_kg_m3 - 1070.0).abs() < f64::EPSILON);
+
+    assert!((catalog["BELL_8048"].isp - 235.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn propellant_load() {
+    let path = write_fixture("propellant", "name: Xenon\ndensity_kg_m3: 5894.0\n");
+
+    let propellant = Propellant::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(propellant.name, "Xenon");
+    assert!((propellant.density_kg_m3 - 5894.0).abs() < f64::EPSILON);
+}